@@ -1,10 +1,21 @@
 // Copyright (C) 2024 Stephane Raux. Distributed under the 0BSD license.
 
-use futures::{Stream, StreamExt};
+use futures::{stream, Stream, StreamExt};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    hash::Hash,
+    pin::Pin,
+    time::{Duration, Instant},
+};
 use tokio::io::AsyncBufReadExt;
 use tokio_stream::wrappers::LinesStream;
 
+/// Recommended quiet period to pass as the `window` of [`events_debounced`], which has no
+/// built-in default.
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(150);
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Event<M> {
     pub message: M,
@@ -63,3 +74,79 @@ pub fn events<M: DeserializeOwned>() -> impl Stream<Item = Event<M>> {
         },
     )
 }
+
+/// Runs `handle` for each event, awaiting the side effect it returns before consuming the next
+/// one.
+pub async fn dispatch<S, M, F, Fut>(events: S, mut handle: F)
+where
+    S: Stream<Item = Event<M>>,
+    F: FnMut(Event<M>) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    events.for_each(move |event| handle(event)).await;
+}
+
+/// Coalesces repeat events for the same message and button, forwarding only the latest one seen
+/// once `window` has passed without another matching event.
+pub fn events_debounced<M, S>(events: S, window: Duration) -> impl Stream<Item = Event<M>>
+where
+    S: Stream<Item = Event<M>>,
+    M: Clone + Eq + Hash,
+{
+    struct State<M, S> {
+        events: Pin<Box<S>>,
+        window: Duration,
+        pending: HashMap<(M, MouseButton), (Event<M>, Instant)>,
+        ready: VecDeque<Event<M>>,
+        ended: bool,
+    }
+
+    let state = State {
+        events: Box::pin(events),
+        window,
+        pending: HashMap::new(),
+        ready: VecDeque::new(),
+        ended: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.ready.pop_front() {
+                return Some((event, state));
+            }
+            if state.ended && state.pending.is_empty() {
+                return None;
+            }
+            let deadline = state.pending.values().map(|(_, deadline)| *deadline).min();
+            let sleep_until_deadline = async {
+                match deadline {
+                    Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                event = state.events.next(), if !state.ended => match event {
+                    Some(event) => {
+                        let key = (event.message.clone(), event.button);
+                        state.pending.insert(key, (event, Instant::now() + state.window));
+                    }
+                    None => state.ended = true,
+                },
+                () = sleep_until_deadline => {
+                    let now = Instant::now();
+                    let expired_keys = state
+                        .pending
+                        .iter()
+                        .filter(|(_, (_, deadline))| *deadline <= now)
+                        .map(|(key, _)| key.clone())
+                        .collect::<Vec<_>>();
+                    for key in expired_keys {
+                        if let Some((event, _)) = state.pending.remove(&key) {
+                            state.ready.push_back(event);
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
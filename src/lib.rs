@@ -1,9 +1,9 @@
 // Copyright (C) 2019-2024 Stephane Raux. Distributed under the 0BSD license.
 
 pub use block::Block;
-pub use event::{events, Event, MouseButton};
+pub use event::{dispatch, events, events_debounced, Event, MouseButton, DEFAULT_DEBOUNCE};
 pub use serialize::serialize_blocks;
-pub use util::{throttle, ticks};
+pub use util::{throttle, ticks, ticks_or};
 
 mod block;
 mod event;
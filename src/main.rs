@@ -1,13 +1,14 @@
-// Copyright (C) 2019-2022 Stephane Raux. Distributed under the 0BSD license.
+// Copyright (C) 2019-2024 Stephane Raux. Distributed under the 0BSD license.
 
 // #![deny(warnings)]
 
 use chrono::Local;
-use desktus::ticks;
+use desktus::{ticks, ticks_or};
 use futures::StreamExt;
 use futuristic::StreamTools;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
+use tokio_stream::wrappers::UnboundedReceiverStream;
 
 #[tokio::main]
 async fn main() {
@@ -38,13 +39,23 @@ async fn main() {
             .ok()
             .map(|d| desktus::views::DiskView::new(d, Message::Ignore, foreground).render())
     });
-    let brightness = ticks(Duration::from_secs(20)).then(|_| async {
+    let (refresh_brightness, refresh_brightness_trigger) = tokio::sync::mpsc::unbounded_channel();
+    let brightness = ticks_or(
+        Duration::from_secs(20),
+        UnboundedReceiverStream::new(refresh_brightness_trigger),
+    )
+    .then(|_| async {
         desktus::sources::brightness()
             .await
             .into_iter()
             .flatten()
             .map(|b| {
-                desktus::views::BrightnessView::new(b, |_| Message::Ignore, foreground).render()
+                desktus::views::BrightnessView::new(
+                    b,
+                    |device: &str| Message::AdjustBrightness(device.to_owned()),
+                    foreground,
+                )
+                .render()
             })
             .collect::<Vec<_>>()
     });
@@ -63,11 +74,33 @@ async fn main() {
                 .collect::<Vec<_>>()
         });
     let output = desktus::serialize_blocks(blocks);
-    let input = desktus::events::<Message>().for_each(|_| async {});
+    let events = desktus::events_debounced(desktus::events::<Message>(), desktus::DEFAULT_DEBOUNCE);
+    let input = desktus::dispatch(events, |event| async move {
+        let Message::AdjustBrightness(device) = event.message else {
+            return;
+        };
+        let step = match event.button {
+            desktus::MouseButton::Up => 5,
+            desktus::MouseButton::Down => -5,
+            _ => return,
+        };
+        let Some(Ok(info)) = desktus::sources::brightness()
+            .await
+            .into_iter()
+            .find(|b| matches!(b, Ok(info) if info.device == device))
+        else {
+            return;
+        };
+        let level = desktus::sources::clamp_level(info.level as i32 + step);
+        if desktus::sources::set_brightness(&device, level).await.is_ok() {
+            let _ = refresh_brightness.send(());
+        }
+    });
     futures::future::join(output, input).await;
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 enum Message {
     Ignore,
+    AdjustBrightness(String),
 }
@@ -1,13 +1,17 @@
 // Copyright (C) 2019-2024 Stephane Raux. Distributed under the 0BSD license.
 
 pub use battery::{battery_state, BatteryState};
-pub use brightness::{brightness, BrightnessInfo};
+pub use brightness::{brightness, clamp_level, set_brightness, BrightnessInfo};
 pub use cpu::cpu_usage;
 pub use disk::{disk_usage, DiskUsage};
 pub use memory::{memory_usage, MemoryUsage};
+pub use net::{NetworkMonitor, NetworkUsage};
+pub use temperature::{temperature, Temperature};
 
 mod battery;
 mod brightness;
 mod cpu;
 mod disk;
 mod memory;
+mod net;
+mod temperature;
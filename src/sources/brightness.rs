@@ -1,4 +1,4 @@
-// Copyright (C) 2022 Stephane Raux. Distributed under the 0BSD license.
+// Copyright (C) 2022-2024 Stephane Raux. Distributed under the 0BSD license.
 
 use brightness::Brightness;
 use futures::{StreamExt, TryStreamExt};
@@ -22,11 +22,31 @@ pub async fn brightness() -> Vec<Result<BrightnessInfo, BrightnessError>> {
                 level,
             })
         })
-        .map_err(BrightnessError)
+        .map_err(BrightnessError::Device)
         .collect()
         .await
 }
 
+pub async fn set_brightness(device: &str, level: u32) -> Result<(), BrightnessError> {
+    let mut devices = brightness::brightness_devices();
+    while let Some(d) = devices.next().await {
+        let d = d.map_err(BrightnessError::Device)?;
+        if d.device_name().await.map_err(BrightnessError::Device)? == device {
+            return d.set(level).await.map_err(BrightnessError::Device);
+        }
+    }
+    Err(BrightnessError::NotFound(device.to_owned()))
+}
+
+/// Clamps a brightness level adjustment to the valid `0..=100` range.
+pub fn clamp_level(level: i32) -> u32 {
+    level.clamp(0, 100) as u32
+}
+
 #[derive(Debug, Error)]
-#[error(transparent)]
-pub struct BrightnessError(brightness::Error);
+pub enum BrightnessError {
+    #[error(transparent)]
+    Device(brightness::Error),
+    #[error("no brightness device named {0:?}")]
+    NotFound(String),
+}
@@ -0,0 +1,65 @@
+// Copyright (C) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Instant};
+use sysinfo::{NetworkExt, System, SystemExt};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct NetworkUsage {
+    pub down: u64,
+    pub up: u64,
+}
+
+/// Tracks cumulative network interface counters across calls to compute throughput.
+#[derive(Debug)]
+pub struct NetworkMonitor {
+    previous: HashMap<String, (u64, u64)>,
+    last_sample: Option<Instant>,
+}
+
+impl NetworkMonitor {
+    pub fn new() -> Self {
+        Self {
+            previous: HashMap::new(),
+            last_sample: None,
+        }
+    }
+
+    /// Samples network throughput, optionally restricted to a single interface.
+    pub fn sample(&mut self, system: &mut System, interface: Option<&str>) -> NetworkUsage {
+        system.refresh_networks_list();
+        system.refresh_networks();
+        let now = Instant::now();
+        let elapsed = self.last_sample.map(|t| now.duration_since(t).as_secs_f64());
+        self.last_sample = Some(now);
+        let (down, up) = system
+            .networks()
+            .iter()
+            .filter(|(name, _)| interface.map_or(true, |interface| name.as_str() == interface))
+            .fold((0, 0), |(down, up), (name, data)| {
+                let received = data.total_received();
+                let transmitted = data.total_transmitted();
+                let previous = self
+                    .previous
+                    .insert(name.clone(), (received, transmitted))
+                    .unwrap_or((received, transmitted));
+                (
+                    down + received.saturating_sub(previous.0),
+                    up + transmitted.saturating_sub(previous.1),
+                )
+            });
+        match elapsed {
+            Some(elapsed) if elapsed > 0.0 => NetworkUsage {
+                down: (down as f64 / elapsed).round() as u64,
+                up: (up as f64 / elapsed).round() as u64,
+            },
+            _ => NetworkUsage::default(),
+        }
+    }
+}
+
+impl Default for NetworkMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (C) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{ComponentExt, System, SystemExt};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Temperature {
+    pub label: String,
+    pub celsius: f32,
+}
+
+/// Returns the temperature of the component whose label contains `label`, or the hottest
+/// component if `label` is `None`.
+pub fn temperature(system: &mut System, label: Option<&str>) -> Option<Temperature> {
+    system.refresh_components_list();
+    system.refresh_components();
+    match label {
+        Some(label) => system
+            .components()
+            .iter()
+            .find(|component| component.label().contains(label)),
+        None => system
+            .components()
+            .iter()
+            .max_by(|a, b| a.temperature().total_cmp(&b.temperature())),
+    }
+    .map(|component| Temperature {
+        label: component.label().to_owned(),
+        celsius: component.temperature(),
+    })
+}
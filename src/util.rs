@@ -1,6 +1,6 @@
 // Copyright (C) 2019-2024 Stephane Raux. Distributed under the 0BSD license.
 
-use futures::{Stream, StreamExt};
+use futures::{stream, Stream, StreamExt};
 use std::time::{Duration, Instant};
 use tokio::time::MissedTickBehavior;
 use tokio_stream::wrappers::IntervalStream;
@@ -15,6 +15,15 @@ pub fn throttle<S: Stream>(d: Duration, stream: S) -> impl Stream<Item = S::Item
     stream.zip(ticks(d)).map(|(x, _)| x)
 }
 
+/// Yields on every tick of period `d` as well as every item produced by `trigger`, so a source
+/// can be refreshed on its regular schedule or on demand.
+pub fn ticks_or<S>(d: Duration, trigger: S) -> impl Stream<Item = ()>
+where
+    S: Stream<Item = ()>,
+{
+    stream::select(ticks(d).map(|_| ()), trigger)
+}
+
 pub fn pie_chart(percentage: u32) -> char {
     const SYMBOLS: &[char] = &[
         '\u{f0130}',
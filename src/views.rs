@@ -6,6 +6,8 @@ pub use cpu::CpuView;
 pub use date::DateView;
 pub use disk::DiskView;
 pub use memory::MemoryView;
+pub use net::NetworkView;
+pub use temperature::TemperatureView;
 pub use time::TimeView;
 
 mod battery;
@@ -14,4 +16,6 @@ mod cpu;
 mod date;
 mod disk;
 mod memory;
+mod net;
+mod temperature;
 mod time;
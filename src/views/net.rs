@@ -0,0 +1,55 @@
+// Copyright (C) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{pretty::Quantity, sources::NetworkUsage, Block};
+use palette::Srgb;
+
+#[derive(Debug)]
+pub struct NetworkView<M> {
+    usage: NetworkUsage,
+    message: M,
+    foreground: Srgb<u8>,
+    critical_usage: u64,
+    critical_background: Srgb<u8>,
+}
+
+impl<M> NetworkView<M> {
+    pub fn new(usage: NetworkUsage, message: M, foreground: Srgb<u8>) -> Self {
+        Self {
+            usage,
+            message,
+            foreground,
+            critical_usage: 50_000_000,
+            critical_background: palette::named::FIREBRICK,
+        }
+    }
+
+    pub fn critical_when_more_than(self, critical_usage: u64) -> Self {
+        Self {
+            critical_usage,
+            ..self
+        }
+    }
+
+    pub fn critical_background(self, background: Srgb<u8>) -> Self {
+        Self {
+            critical_background: background,
+            ..self
+        }
+    }
+}
+
+impl<M: Clone> NetworkView<M> {
+    pub fn render(&self) -> Block<M> {
+        let down = Quantity::new(self.usage.down as f64, "B/s");
+        let up = Quantity::new(self.usage.up as f64, "B/s");
+        let critical = self.usage.down + self.usage.up > self.critical_usage;
+        Block {
+            background: critical.then(|| self.critical_background),
+            ..Block::new(
+                format!("\u{f0ab2} {down} \u{f0aa8} {up}"),
+                self.foreground,
+                self.message.clone(),
+            )
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2024 Stephane Raux. Distributed under the 0BSD license.
+
+use crate::{sources::Temperature, Block};
+use palette::Srgb;
+
+#[derive(Debug)]
+pub struct TemperatureView<M> {
+    temperature: Temperature,
+    message: M,
+    foreground: Srgb<u8>,
+    critical_celsius: f32,
+    critical_background: Srgb<u8>,
+}
+
+impl<M> TemperatureView<M> {
+    pub fn new(temperature: Temperature, message: M, foreground: Srgb<u8>) -> Self {
+        Self {
+            temperature,
+            message,
+            foreground,
+            critical_celsius: 80.0,
+            critical_background: palette::named::FIREBRICK,
+        }
+    }
+
+    pub fn critical_when_more_than(self, critical_celsius: f32) -> Self {
+        Self {
+            critical_celsius,
+            ..self
+        }
+    }
+
+    pub fn critical_background(self, background: Srgb<u8>) -> Self {
+        Self {
+            critical_background: background,
+            ..self
+        }
+    }
+}
+
+impl<M: Clone> TemperatureView<M> {
+    pub fn render(&self) -> Block<M> {
+        let celsius = self.temperature.celsius;
+        Block {
+            background: (celsius > self.critical_celsius).then(|| self.critical_background),
+            ..Block::new(
+                format!("\u{f058e} {celsius:.0}\u{b0}C"),
+                self.foreground,
+                self.message.clone(),
+            )
+        }
+    }
+}